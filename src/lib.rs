@@ -1,50 +1,85 @@
+#![allow(clippy::new_without_default)]
+#![allow(clippy::tabs_in_doc_comments)]
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
+
 /// Stores a mean and other necessary state for it to be updated with additional values
 /// # Examples
 /// ```
 /// use ml_math::MeanIncrementor;
 /// // Initialize the incrementor
-/// let mut mean_inc = MeanIncrementor::new();
+/// let mut mean_incr = MeanIncrementor::new();
 /// // Add some values
 ///	mean_incr.add(0f64);
 ///	mean_incr.add(1f64);
 /// // Get the mean
-///	assert_eq!(0.5f64, mean_incr.mean);
+///	assert_eq!(0.5f64, mean_incr.mean());
 /// // Add more values
 ///	mean_incr.add(1f64);
 ///	mean_incr.add(2f64);
 /// // Get the updated mean
-///	assert_eq!(0.5f64, mean_incr.mean);
+///	assert_eq!(1f64, mean_incr.mean());
 /// ```
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MeanIncrementor {
 	mean: f64,
-	count: u32
+	count: u32,
+	weight_sum: f64
 }
 
 impl MeanIncrementor {
 	pub fn new() -> MeanIncrementor {
-		MeanIncrementor {mean: 0f64, count: 0}
+		MeanIncrementor {mean: 0f64, count: 0, weight_sum: 0f64}
 	}
-	
+
 	/// Update the mean with another value whose weight will be determined by the number of previously added values
 	pub fn add(&mut self, value: f64) {
-		if self.count == 0 {
-			// If we have no values yet the mean is simply the first value
-			self.mean = value;
-		} else {
-            let weight_per_value = 1f64 / (self.count + 1) as f64;
-            self.mean = self.mean * (1.0 - weight_per_value)  + value * weight_per_value;
-		}
+		self.add_weighted(value, 1f64);
+	}
+
+	/// Update the mean with another value, weighted by `weight` instead of treating every observation equally.
+	/// `add(value)` is simply the `weight == 1.0` case of this, using the weighted Welford recurrence.
+	pub fn add_weighted(&mut self, value: f64, weight: f64) {
+		self.weight_sum += weight;
+		self.mean += (weight / self.weight_sum) * (value - self.mean);
 		self.count += 1;
 	}
-	
+
+	/// Fold the state of another incrementor into this one, as if every value given to `other` had
+	/// instead been given to `self`. Useful for combining partial means computed on separate partitions.
+	pub fn merge(&mut self, other: &MeanIncrementor) {
+		if other.weight_sum == 0f64 {
+			return;
+		}
+		if self.weight_sum == 0f64 {
+			self.mean = other.mean;
+			self.count = other.count;
+			self.weight_sum = other.weight_sum;
+			return;
+		}
+
+		let total_weight = self.weight_sum + other.weight_sum;
+		self.mean = (self.weight_sum * self.mean + other.weight_sum * other.mean) / total_weight;
+		self.count += other.count;
+		self.weight_sum = total_weight;
+	}
+
 	pub fn mean(&self) -> f64 {
 		self.mean
 	}
-	
+
+	/// The number of values added, each counted once regardless of its weight (frequency-weighted normalization)
 	pub fn count(&self) -> u32 {
 		self.count
 	}
+
+	/// The sum of weights of the values added (reliability-weighted normalization). Equal to `count()` when
+	/// every value was added with `add` or `add_weighted(value, 1.0)`.
+	pub fn total_weight(&self) -> f64 {
+		self.weight_sum
+	}
 }
 
 #[test] 
@@ -58,13 +93,43 @@ fn test_mean_incrementor() {
 	assert_eq!(0.5f64, mean_incr.mean());
 }
 
+#[test]
+fn test_mean_incrementor_merge() {
+	let mut partition_a = MeanIncrementor::new();
+	partition_a.add(0f64);
+	partition_a.add(1f64);
+
+	let mut partition_b = MeanIncrementor::new();
+	partition_b.add(2f64);
+	partition_b.add(3f64);
+
+	partition_a.merge(&partition_b);
+	assert_eq!(1.5f64, partition_a.mean());
+	assert_eq!(4, partition_a.count());
+}
+
+#[test]
+fn test_mean_incrementor_add_weighted() {
+	let mut mean_incr = MeanIncrementor::new();
+
+	mean_incr.add_weighted(0f64, 1f64);
+	mean_incr.add_weighted(2f64, 3f64);
+
+	assert_eq!(1.5f64, mean_incr.mean());
+	assert_eq!(2, mean_incr.count());
+	assert_eq!(4f64, mean_incr.total_weight());
+}
+
 /// Stores a variance and other necessary state for it to be updated with additional values.
-/// [See Details on the Formula](http://math.stackexchange.com/questions/102978/incremental-computation-of-standard-deviation)
+/// Uses Welford's online algorithm, which keeps a running mean and sum of squared
+/// deviations `m2` and avoids the catastrophic cancellation that a naive rescaling
+/// recurrence suffers from on long streams.
+/// [See Details on the Formula](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm)
 /// # Examples
 /// ```
 /// use ml_math::VarianceIncrementor;
 /// // Initialize the incrementor
-///	let mut variance_inc = VarianceIncrementor::new();
+///	let mut variance_incr = VarianceIncrementor::new();
 /// // Add some values
 ///	variance_incr.add(0f64);
 ///	variance_incr.add(1f64);
@@ -76,38 +141,93 @@ fn test_mean_incrementor() {
 ///	assert_eq!(1f64, variance_incr.variance());
 /// ```
 #[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VarianceIncrementor {
-	variance: f64,
-	mean_incrementor: MeanIncrementor
+	avg: f64,
+	m2: f64,
+	count: u32,
+	weight_sum: f64
 }
 
 impl VarianceIncrementor {
 	pub fn new() -> VarianceIncrementor {
-        VarianceIncrementor {variance: 0f64, mean_incrementor: MeanIncrementor::new()}
+        VarianceIncrementor {avg: 0f64, m2: 0f64, count: 0, weight_sum: 0f64}
 	}
-	
+
+	/// Update the running mean and sum of squared deviations with another value using Welford's recurrence
 	pub fn add(&mut self, value: f64) {
-		let n = self.mean_incrementor.count();
-        let previous_mean = self.mean_incrementor.mean();
-        self.mean_incrementor.add(value);
-
-		if n == 0 {
-			self.variance = 0f64;
-        } else {
-        	self.variance = (n - 1) as f64 / n as f64 * self.variance + (value - previous_mean).powi(2) / (n + 1) as f64;
+		self.add_weighted(value, 1f64);
+	}
+
+	/// Update the running mean and sum of squared deviations with another value, weighted by `weight`
+	/// instead of treating every observation equally. `add(value)` is simply the `weight == 1.0` case of
+	/// this, using the weighted Welford recurrence.
+	pub fn add_weighted(&mut self, value: f64, weight: f64) {
+		self.count += 1;
+		self.weight_sum += weight;
+		let old_avg = self.avg;
+		self.avg += (weight / self.weight_sum) * (value - old_avg);
+		self.m2 += weight * (value - old_avg) * (value - self.avg);
+	}
+
+	/// Fold the state of another incrementor into this one, as if every value given to `other` had
+	/// instead been given to `self`. Uses the parallel variant of Welford's algorithm so partial
+	/// variances computed on separate partitions can be combined exactly.
+	pub fn merge(&mut self, other: &VarianceIncrementor) {
+		if other.weight_sum == 0f64 {
+			return;
 		}
+		if self.weight_sum == 0f64 {
+			self.avg = other.avg;
+			self.m2 = other.m2;
+			self.count = other.count;
+			self.weight_sum = other.weight_sum;
+			return;
+		}
+
+		let total_weight = self.weight_sum + other.weight_sum;
+		let delta = other.avg - self.avg;
+		self.m2 += other.m2 + delta * delta * (self.weight_sum * other.weight_sum / total_weight);
+		self.avg += delta * (other.weight_sum / total_weight);
+		self.count += other.count;
+		self.weight_sum = total_weight;
 	}
-	
+
+	/// The sample variance (Bessel's correction, divides by `total_weight() - 1`). This is what
+	/// `variance()` has always returned.
 	pub fn variance(&self) -> f64 {
-		self.variance
+		self.sample_variance()
+	}
+
+	/// The sample variance, i.e. the unbiased estimator of the population variance from a sample
+	pub fn sample_variance(&self) -> f64 {
+		if self.weight_sum < 2f64 {
+			return 0f64;
+		}
+		self.m2 / (self.weight_sum - 1f64)
 	}
-	
+
+	/// The variance of the values seen so far, treated as the entire population (divides by `total_weight()`)
+	pub fn population_variance(&self) -> f64 {
+		if self.weight_sum == 0f64 {
+			return 0f64;
+		}
+		self.m2 / self.weight_sum
+	}
+
 	pub fn mean(&self) -> f64 {
-		self.mean_incrementor.mean()
+		self.avg
 	}
-	
+
+	/// The number of values added, each counted once regardless of its weight (frequency-weighted normalization)
 	pub fn count(&self) -> u32 {
-		self.mean_incrementor.count()
+		self.count
+	}
+
+	/// The sum of weights of the values added (reliability-weighted normalization). Equal to `count()` when
+	/// every value was added with `add` or `add_weighted(value, 1.0)`.
+	pub fn total_weight(&self) -> f64 {
+		self.weight_sum
 	}
 }
 
@@ -121,4 +241,433 @@ fn variance_incrementor() {
 
 	variance_incr.add(2f64);
 	assert_eq!(1f64, variance_incr.variance());
+}
+
+#[test]
+fn test_variance_incrementor_merge() {
+	let mut partition_a = VarianceIncrementor::new();
+	partition_a.add(0f64);
+	partition_a.add(1f64);
+
+	let mut partition_b = VarianceIncrementor::new();
+	partition_b.add(2f64);
+
+	partition_a.merge(&partition_b);
+	assert_eq!(1f64, partition_a.mean());
+	assert_eq!(1f64, partition_a.variance());
+	assert_eq!(3, partition_a.count());
+}
+
+#[test]
+fn test_variance_incrementor_add_weighted() {
+	let mut variance_incr = VarianceIncrementor::new();
+
+	variance_incr.add_weighted(0f64, 1f64);
+	variance_incr.add_weighted(2f64, 3f64);
+
+	assert_eq!(1.5f64, variance_incr.mean());
+	assert_eq!(1f64, variance_incr.variance());
+	assert_eq!(0.75f64, variance_incr.population_variance());
+	assert_eq!(4f64, variance_incr.total_weight());
+}
+
+/// Stores a skewness and other necessary state for it to be updated with additional values.
+/// Extends the single-pass approach used by `VarianceIncrementor` with a running third moment `m3`,
+/// so the (sample) skewness can be read off in constant memory without buffering the stream.
+/// [See Details on the Formula](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Higher-order_statistics)
+/// # Examples
+/// ```
+/// use ml_math::SkewnessIncrementor;
+/// // Initialize the incrementor
+/// let mut skewness_incr = SkewnessIncrementor::new();
+/// // Add some values with a long right tail
+///	skewness_incr.add(1f64);
+///	skewness_incr.add(2f64);
+///	skewness_incr.add(2f64);
+///	skewness_incr.add(3f64);
+///	skewness_incr.add(10f64);
+/// // Get the skewness
+///	assert!((skewness_incr.skewness() - 1.3608927294433224f64).abs() < 1e-9);
+/// ```
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SkewnessIncrementor {
+	avg: f64,
+	m2: f64,
+	m3: f64,
+	count: u32
+}
+
+impl SkewnessIncrementor {
+	pub fn new() -> SkewnessIncrementor {
+		SkewnessIncrementor {avg: 0f64, m2: 0f64, m3: 0f64, count: 0}
+	}
+
+	/// Update the running moments with another value using the online higher-order-moment recurrence
+	pub fn add(&mut self, value: f64) {
+		self.count += 1;
+		let n = self.count as f64;
+		let delta = value - self.avg;
+		let delta_n = delta / n;
+		let term1 = delta * delta_n * (n - 1.0);
+
+		self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+		self.m2 += term1;
+		self.avg += delta_n;
+	}
+
+	/// The skewness of the values seen so far
+	pub fn skewness(&self) -> f64 {
+		if self.count < 2 || self.m2 == 0f64 {
+			return 0f64;
+		}
+		(self.count as f64).sqrt() * self.m3 / self.m2.powf(1.5)
+	}
+
+	pub fn mean(&self) -> f64 {
+		self.avg
+	}
+
+	pub fn count(&self) -> u32 {
+		self.count
+	}
+}
+
+#[test]
+fn test_skewness_incrementor() {
+	let mut skewness_incr = SkewnessIncrementor::new();
+
+	skewness_incr.add(1f64);
+	skewness_incr.add(2f64);
+	skewness_incr.add(2f64);
+	skewness_incr.add(3f64);
+	skewness_incr.add(10f64);
+
+	assert!((skewness_incr.skewness() - 1.3608927294433224f64).abs() < 1e-9);
+}
+
+/// Stores a kurtosis and other necessary state for it to be updated with additional values.
+/// Extends the single-pass approach used by `VarianceIncrementor` with running third and fourth
+/// moments `m3`/`m4`, so the excess kurtosis can be read off in constant memory without buffering
+/// the stream.
+/// [See Details on the Formula](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Higher-order_statistics)
+/// # Examples
+/// ```
+/// use ml_math::KurtosisIncrementor;
+/// // Initialize the incrementor
+/// let mut kurtosis_incr = KurtosisIncrementor::new();
+/// // Add some values with a long right tail
+///	kurtosis_incr.add(1f64);
+///	kurtosis_incr.add(2f64);
+///	kurtosis_incr.add(2f64);
+///	kurtosis_incr.add(3f64);
+///	kurtosis_incr.add(10f64);
+/// // Get the excess kurtosis
+///	assert!((kurtosis_incr.kurtosis() - 0.06803663293572226f64).abs() < 1e-9);
+/// ```
+#[derive(Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KurtosisIncrementor {
+	avg: f64,
+	m2: f64,
+	m3: f64,
+	m4: f64,
+	count: u32
+}
+
+impl KurtosisIncrementor {
+	pub fn new() -> KurtosisIncrementor {
+		KurtosisIncrementor {avg: 0f64, m2: 0f64, m3: 0f64, m4: 0f64, count: 0}
+	}
+
+	/// Update the running moments with another value using the online higher-order-moment recurrence
+	pub fn add(&mut self, value: f64) {
+		self.count += 1;
+		let n = self.count as f64;
+		let delta = value - self.avg;
+		let delta_n = delta / n;
+		let delta_n2 = delta_n * delta_n;
+		let term1 = delta * delta_n * (n - 1.0);
+
+		self.m4 += term1 * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2 - 4.0 * delta_n * self.m3;
+		self.m3 += term1 * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+		self.m2 += term1;
+		self.avg += delta_n;
+	}
+
+	/// The excess kurtosis of the values seen so far (zero for a normal distribution)
+	pub fn kurtosis(&self) -> f64 {
+		if self.count < 2 || self.m2 == 0f64 {
+			return 0f64;
+		}
+		self.count as f64 * self.m4 / (self.m2 * self.m2) - 3.0
+	}
+
+	pub fn mean(&self) -> f64 {
+		self.avg
+	}
+
+	pub fn count(&self) -> u32 {
+		self.count
+	}
+}
+
+#[test]
+fn test_kurtosis_incrementor() {
+	let mut kurtosis_incr = KurtosisIncrementor::new();
+
+	kurtosis_incr.add(1f64);
+	kurtosis_incr.add(2f64);
+	kurtosis_incr.add(2f64);
+	kurtosis_incr.add(3f64);
+	kurtosis_incr.add(10f64);
+
+	assert!((kurtosis_incr.kurtosis() - 0.06803663293572226f64).abs() < 1e-9);
+}
+
+/// Stores an exponentially weighted mean, which gives recent observations more weight than older
+/// ones instead of weighting every sample equally like `MeanIncrementor` does. Good for tracking a
+/// drifting signal in constant memory, e.g. online anomaly detection or time series monitoring.
+/// # Examples
+/// ```
+/// use ml_math::EwmaIncrementor;
+/// // Initialize the incrementor with a smoothing factor
+/// let mut ewma_incr = EwmaIncrementor::new(0.5f64);
+/// // Add some values
+///	ewma_incr.add(0f64);
+///	ewma_incr.add(2f64);
+/// // Get the mean
+///	assert_eq!(1f64, ewma_incr.mean());
+/// ```
+#[derive(Copy, Clone)]
+pub struct EwmaIncrementor {
+	alpha: f64,
+	mean: f64,
+	initialized: bool
+}
+
+impl EwmaIncrementor {
+	pub fn new(alpha: f64) -> EwmaIncrementor {
+		EwmaIncrementor {alpha, mean: 0f64, initialized: false}
+	}
+
+	/// Update the mean, weighting `value` by `alpha` against the previously accumulated mean
+	pub fn add(&mut self, value: f64) {
+		if !self.initialized {
+			// The first value seeds the mean, there's nothing to smooth against yet
+			self.mean = value;
+			self.initialized = true;
+		} else {
+			self.mean = self.alpha * value + (1.0 - self.alpha) * self.mean;
+		}
+	}
+
+	pub fn mean(&self) -> f64 {
+		self.mean
+	}
+}
+
+#[test]
+fn test_ewma_incrementor() {
+	let mut ewma_incr = EwmaIncrementor::new(0.5f64);
+
+	ewma_incr.add(0f64);
+	assert_eq!(0f64, ewma_incr.mean());
+
+	ewma_incr.add(2f64);
+	assert_eq!(1f64, ewma_incr.mean());
+}
+
+/// Stores an exponentially weighted variance, the variance counterpart to `EwmaIncrementor`. Like
+/// the mean it tracks, older observations decay away geometrically rather than being weighted
+/// equally, so it stays responsive on non-stationary streams.
+#[derive(Copy, Clone)]
+pub struct EwmaVarianceIncrementor {
+	alpha: f64,
+	mean: f64,
+	s: f64,
+	initialized: bool
+}
+
+impl EwmaVarianceIncrementor {
+	pub fn new(alpha: f64) -> EwmaVarianceIncrementor {
+		EwmaVarianceIncrementor {alpha, mean: 0f64, s: 0f64, initialized: false}
+	}
+
+	/// Update the mean and variance, weighting `value` by `alpha` against the previously accumulated state
+	pub fn add(&mut self, value: f64) {
+		if !self.initialized {
+			// The first value seeds the mean, there's nothing to smooth against yet
+			self.mean = value;
+			self.initialized = true;
+			return;
+		}
+
+		let old_mean = self.mean;
+		self.mean = self.alpha * value + (1.0 - self.alpha) * self.mean;
+		self.s = (1.0 - self.alpha) * (self.s + self.alpha * (value - old_mean).powi(2));
+	}
+
+	pub fn variance(&self) -> f64 {
+		self.s
+	}
+
+	pub fn mean(&self) -> f64 {
+		self.mean
+	}
+}
+
+#[test]
+fn test_ewma_variance_incrementor() {
+	let mut ewma_var_incr = EwmaVarianceIncrementor::new(0.5f64);
+
+	ewma_var_incr.add(0f64);
+	ewma_var_incr.add(2f64);
+	ewma_var_incr.add(2f64);
+
+	assert_eq!(0.75f64, ewma_var_incr.variance());
+}
+
+/// Estimates an arbitrary quantile (e.g. the median at `p = 0.5`) of a stream in constant memory
+/// using the P² algorithm, which tracks five markers (min, the target quantile estimate, one interior
+/// estimate either side of it, and max) and adjusts their heights as new values arrive instead of
+/// buffering the whole stream.
+/// [See Details on the Formula](https://www.cse.wustl.edu/~jain/papers/ftp/psqr.pdf)
+/// # Examples
+/// ```
+/// use ml_math::QuantileIncrementor;
+/// // Initialize a median estimator
+/// let mut median_incr = QuantileIncrementor::new(0.5f64);
+///	median_incr.add(3f64);
+///	median_incr.add(1f64);
+///	median_incr.add(4f64);
+///	median_incr.add(1f64);
+///	median_incr.add(5f64);
+///	assert_eq!(3f64, median_incr.quantile());
+/// ```
+#[derive(Copy, Clone)]
+pub struct QuantileIncrementor {
+	p: f64,
+	q: [f64; 5],
+	n: [i64; 5],
+	np: [f64; 5],
+	dn: [f64; 5],
+	count: u32
+}
+
+impl QuantileIncrementor {
+	pub fn new(p: f64) -> QuantileIncrementor {
+		QuantileIncrementor {
+			p,
+			q: [0f64; 5],
+			n: [0i64; 5],
+			np: [0f64; 5],
+			dn: [0f64, p / 2.0, p, (1.0 + p) / 2.0, 1.0],
+			count: 0
+		}
+	}
+
+	/// Feed another value from the stream into the estimator
+	pub fn add(&mut self, value: f64) {
+		if self.count < 5 {
+			self.q[self.count as usize] = value;
+			self.count += 1;
+			if self.count == 5 {
+				self.q.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+				for i in 0..5 {
+					self.n[i] = (i + 1) as i64;
+				}
+				self.np = [1.0, 1.0 + 2.0 * self.p, 1.0 + 4.0 * self.p, 3.0 + 2.0 * self.p, 5.0];
+			}
+			return;
+		}
+
+		let k = if value < self.q[0] {
+			self.q[0] = value;
+			0
+		} else if value >= self.q[4] {
+			self.q[4] = value;
+			3
+		} else {
+			let mut cell = 3;
+			for i in 0..4 {
+				if self.q[i] <= value && value < self.q[i + 1] {
+					cell = i;
+					break;
+				}
+			}
+			cell
+		};
+
+		for i in (k + 1)..5 {
+			self.n[i] += 1;
+		}
+		for i in 0..5 {
+			self.np[i] += self.dn[i];
+		}
+
+		for i in 1..4 {
+			let d = self.np[i] - self.n[i] as f64;
+			if (d >= 1.0 && self.n[i + 1] - self.n[i] > 1) || (d <= -1.0 && self.n[i - 1] - self.n[i] < -1) {
+				let d_sign: i64 = if d >= 0.0 {1} else {-1};
+				let qn = self.parabolic(i, d_sign);
+
+				if self.q[i - 1] < qn && qn < self.q[i + 1] {
+					self.q[i] = qn;
+				} else {
+					self.q[i] = self.linear(i, d_sign);
+				}
+				self.n[i] += d_sign;
+			}
+		}
+	}
+
+	/// The parabolic marker height prediction, used when it stays between its neighbours
+	fn parabolic(&self, i: usize, d: i64) -> f64 {
+		let d = d as f64;
+		let n_prev = self.n[i - 1] as f64;
+		let n_cur = self.n[i] as f64;
+		let n_next = self.n[i + 1] as f64;
+
+		self.q[i] + d / (n_next - n_prev) * (
+			(n_cur - n_prev + d) * (self.q[i + 1] - self.q[i]) / (n_next - n_cur)
+			+ (n_next - n_cur - d) * (self.q[i] - self.q[i - 1]) / (n_cur - n_prev)
+		)
+	}
+
+	/// The linear marker height fallback, used when the parabolic prediction would break monotonicity
+	fn linear(&self, i: usize, d: i64) -> f64 {
+		let target = (i as i64 + d) as usize;
+		self.q[i] + (d as f64) * (self.q[target] - self.q[i]) / (self.n[target] as f64 - self.n[i] as f64)
+	}
+
+	/// The current estimate of the `p`-quantile. Until five values have been added the values are
+	/// not yet sorted, and this returns the third value added (0 if fewer than three were added).
+	pub fn quantile(&self) -> f64 {
+		self.q[2]
+	}
+}
+
+#[test]
+fn test_quantile_incrementor() {
+	let mut median_incr = QuantileIncrementor::new(0.5f64);
+
+	median_incr.add(3f64);
+	median_incr.add(1f64);
+	median_incr.add(4f64);
+	median_incr.add(1f64);
+	median_incr.add(5f64);
+
+	assert_eq!(3f64, median_incr.quantile());
+}
+
+#[test]
+fn test_quantile_incrementor_converges() {
+	let mut median_incr = QuantileIncrementor::new(0.5f64);
+
+	for i in 1..100 {
+		median_incr.add(i as f64);
+	}
+
+	assert!((median_incr.quantile() - 50f64).abs() < 5f64);
 }
\ No newline at end of file